@@ -1,4 +1,17 @@
-#![feature(core_intrinsics, rustc_attrs, iter_advance_by, try_trait_v2, try_blocks)]
+#![feature(
+    core_intrinsics,
+    rustc_attrs,
+    iter_advance_by,
+    try_trait_v2,
+    try_blocks,
+    maybe_uninit_uninit_array,
+    maybe_uninit_array_assume_init,
+    trusted_len,
+    inplace_iteration,
+    min_specialization
+)]
+
+use std::iter::{FusedIterator, InPlaceIterable, SourceIter, TrustedLen};
 
 // Reimplentation of std::iter::Skip to hack around `n`
 #[derive(Clone, Debug)]
@@ -138,6 +151,84 @@ where
     }
 }
 
+impl<I> ExactSizeIterator for Skip<I>
+where
+    I: ExactSizeIterator,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len().saturating_sub(self.n)
+    }
+}
+
+impl<I> DoubleEndedIterator for Skip<I>
+where
+    I: DoubleEndedIterator + ExactSizeIterator,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<I::Item> {
+        let len = self.len();
+        if len > 0 { self.iter.next_back() } else { None }
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<I::Item> {
+        let len = self.len();
+        if n < len {
+            self.iter.nth_back(n)
+        } else {
+            // Drain the tail down to the skip boundary so a later `next`/`next_back`
+            // doesn't yield anything from inside the skipped prefix.
+            if len > 0 {
+                self.iter.nth_back(len - 1);
+            }
+            None
+        }
+    }
+
+    #[inline]
+    fn try_rfold<Acc, Fold, R>(&mut self, init: Acc, mut fold: Fold) -> R
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, Self::Item) -> R,
+        R: std::ops::Try<Output = Acc>,
+    {
+        let mut acc = init;
+        for _ in 0..self.len() {
+            match self.iter.next_back() {
+                Some(x) => match std::ops::Try::branch(fold(acc, x)) {
+                    std::ops::ControlFlow::Continue(a) => acc = a,
+                    std::ops::ControlFlow::Break(r) => return std::ops::FromResidual::from_residual(r),
+                },
+                None => break,
+            }
+        }
+        try { acc }
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for Skip<I> {}
+
+// SAFETY: `Skip` yields exactly `self.iter.len() - n` items (saturating), which
+// `TrustedLen` on the source already guarantees is reported accurately.
+unsafe impl<I: TrustedLen> TrustedLen for Skip<I> {}
+
+unsafe impl<I> SourceIter for Skip<I>
+where
+    I: SourceIter,
+{
+    type Source = I::Source;
+
+    #[inline]
+    unsafe fn as_inner(&mut self) -> &mut I::Source {
+        // SAFETY: unsafe function forwarding to an unsafe function with the
+        // same requirements.
+        unsafe { self.iter.as_inner() }
+    }
+}
+
+unsafe impl<I: InPlaceIterable> InPlaceIterable for Skip<I> {}
+
 
 use std::{collections::VecDeque, fmt::Debug};
 
@@ -164,36 +255,119 @@ impl<T> Debug for SkakTaken<T> where
 impl<T> Iterator for SkakTaken<T> {
     type Item = T;
 
+    #[inline]
     fn next(&mut self) -> Option<Self::Item> {
         self.items.pop_front()
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.items.len();
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.items.len()
+    }
+
+    #[inline]
+    fn try_fold<Acc, Fold, R>(&mut self, init: Acc, mut fold: Fold) -> R
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, Self::Item) -> R,
+        R: std::ops::Try<Output = Acc>,
+    {
+        let mut acc = init;
+        // Popping before folding means a panicking `fold` still leaves
+        // already-consumed elements removed from `items`.
+        while let Some(x) = self.items.pop_front() {
+            match std::ops::Try::branch(fold(acc, x)) {
+                std::ops::ControlFlow::Continue(a) => acc = a,
+                std::ops::ControlFlow::Break(r) => return std::ops::FromResidual::from_residual(r),
+            }
+        }
+        try { acc }
+    }
+
+    #[inline]
+    fn fold<Acc, Fold>(mut self, init: Acc, mut fold: Fold) -> Acc
+    where
+        Fold: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut acc = init;
+        while let Some(x) = self.items.pop_front() {
+            acc = fold(acc, x);
+        }
+        acc
+    }
+
+    #[inline]
+    fn advance_by(&mut self, n: usize) -> Result<(), usize> {
+        // `Err(len)` reports the number of steps actually advanced before
+        // running out, which is what `Err` means in this `Result<(), usize>`
+        // form of `Iterator::advance_by`, and matches `Skip::advance_by` above.
+        let len = self.items.len();
+        if len < n {
+            self.items.clear();
+            Err(len)
+        } else {
+            self.items.drain(..n);
+            Ok(())
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for SkakTaken<T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.items.len()
+    }
+}
+
+impl<T> DoubleEndedIterator for SkakTaken<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.items.pop_back()
+    }
 }
 
 impl<I> Skak<I> where
-	I: Iterator + Clone,
+	I: Iterator,
 {
-	pub fn new(iter: I, index: usize) -> (SkakTaken<I::Item>, Skak<Skip<I>>) {
-        let taken = iter.clone().take(index).collect::<VecDeque<I::Item>>();
+    // Pulls `index` items directly out of `iter`, advancing it by exactly that
+    // many elements (or fewer, if `iter` runs out first). Unlike the old clone-and-take approach this works for
+    // iterators that can't be replayed from the start (file handles, sockets, ...).
+    fn drain(iter: &mut I, index: usize) -> VecDeque<I::Item> {
+        let mut buf = VecDeque::with_capacity(index);
+        for _ in 0..index {
+            match iter.next() {
+                Some(x) => buf.push_back(x),
+                None => break,
+            }
+        }
+        buf
+    }
+
+	pub fn new(mut iter: I, index: usize) -> (SkakTaken<I::Item>, Skak<I>) {
+        let taken = Self::drain(&mut iter, index);
         (
             SkakTaken {
-                items: taken, 
+                items: taken,
             },
             Skak {
-                iter: Skip::new(iter, index)
+                iter
             }
         )
 	}
 
-    pub fn skip(mut iter: Skak<Skip<I>>, index: usize) -> (SkakTaken<I::Item>, Skak<Skip<I>>) {
-        let taken = iter.clone().take(index).collect::<VecDeque<I::Item>>();
-        iter.iter.n += index;
+    pub fn skip(mut iter: Skak<I>, index: usize) -> (SkakTaken<I::Item>, Skak<I>) {
+        let taken = Self::drain(&mut iter.iter, index);
         (
             SkakTaken {
-                items: taken, 
+                items: taken,
             },
-            Skak {
-                iter: iter.iter
-            }
+            iter
         )
     }
 }
@@ -212,6 +386,194 @@ impl<I> Iterator for Skak<I> where
     }
 }
 
+impl<I: Iterator + FusedIterator> FusedIterator for Skak<I> {}
+
+// SAFETY: `Skak::next` forwards straight to the untouched source iterator, so
+// its length reporting is exactly as trustworthy as `I`'s.
+unsafe impl<I: Iterator + TrustedLen> TrustedLen for Skak<I> {}
+
+unsafe impl<I> SourceIter for Skak<I>
+where
+    I: Iterator + SourceIter,
+{
+    type Source = I::Source;
+
+    #[inline]
+    unsafe fn as_inner(&mut self) -> &mut I::Source {
+        // SAFETY: unsafe function forwarding to an unsafe function with the
+        // same requirements.
+        unsafe { self.iter.as_inner() }
+    }
+}
+
+unsafe impl<I: Iterator + InPlaceIterable> InPlaceIterable for Skak<I> {}
+
+use std::mem::MaybeUninit;
+
+/// A fixed-size counterpart to [`SkakTaken`]: groups of `N` items pulled off
+/// an iterator with no per-chunk heap allocation. Built with
+/// [`Skak::array_chunks`].
+pub struct ArrayChunks<I, const N: usize>
+where
+    I: Iterator,
+{
+    iter: I,
+    remainder: VecDeque<I::Item>,
+}
+
+impl<I, const N: usize> ArrayChunks<I, N>
+where
+    I: Iterator,
+{
+    fn new(iter: I) -> Self {
+        ArrayChunks {
+            iter,
+            remainder: VecDeque::new(),
+        }
+    }
+
+    /// The up-to-`N-1` leftover items that didn't form a complete chunk,
+    /// populated once iteration has run out of source elements.
+    pub fn into_remainder(self) -> VecDeque<I::Item> {
+        self.remainder
+    }
+}
+
+// Drops the already-initialized `arr[..filled]` prefix if we unwind out of the
+// fill loop below (e.g. `self.iter.next()` panics mid-chunk), so a partial
+// chunk never leaks. Must be defused with `mem::forget` once `arr` is handed
+// off to code that takes ownership of its slots itself.
+struct Guard<'a, T> {
+    arr: &'a mut [MaybeUninit<T>],
+    filled: usize,
+}
+
+impl<'a, T> Drop for Guard<'a, T> {
+    fn drop(&mut self) {
+        // SAFETY: every slot below `self.filled` was written by `next` before
+        // this guard could be dropped, and `self.filled` only grows after a
+        // slot is actually written.
+        for slot in &mut self.arr[..self.filled] {
+            unsafe { slot.assume_init_drop() };
+        }
+    }
+}
+
+impl<I, const N: usize> Iterator for ArrayChunks<I, N>
+where
+    I: Iterator,
+{
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut arr: [MaybeUninit<I::Item>; N] = MaybeUninit::uninit_array();
+        let mut guard = Guard {
+            arr: &mut arr,
+            filled: 0,
+        };
+
+        while guard.filled < N {
+            match self.iter.next() {
+                Some(item) => {
+                    guard.arr[guard.filled].write(item);
+                    guard.filled += 1;
+                }
+                None => break,
+            }
+        }
+
+        let filled = guard.filled;
+        // The fill loop is done, so nothing below can unwind mid-chunk;
+        // defuse the guard so it doesn't double-drop the slots `arr` itself
+        // hands off below.
+        std::mem::forget(guard);
+
+        if filled == N {
+            // SAFETY: every slot in `arr` was just written above.
+            Some(unsafe { MaybeUninit::array_assume_init(arr) })
+        } else {
+            // SAFETY: only the first `filled` slots were written.
+            for slot in &mut arr[..filled] {
+                self.remainder
+                    .push_back(unsafe { slot.assume_init_read() });
+            }
+            None
+        }
+    }
+}
+
+impl<I> Skak<I>
+where
+    I: Iterator,
+{
+    pub fn array_chunks<const N: usize>(iter: I) -> ArrayChunks<I, N> {
+        ArrayChunks::new(iter)
+    }
+
+    /// Overlapping (or gapped) chunking: each window captures `size` items but
+    /// the source only advances by `step`. `step < size` shares `size - step`
+    /// items between consecutive windows; `step > size` skips a gap between
+    /// them. `step == size` degenerates to the non-overlapping chunking of
+    /// [`Skak::new`]/[`Skak::skip`].
+    pub fn windows(iter: I, size: usize, step: usize) -> Windows<I> {
+        assert!(step > 0, "Skak::windows: `step` must be greater than zero");
+        Windows {
+            iter,
+            size,
+            step,
+            buf: VecDeque::with_capacity(size),
+        }
+    }
+}
+
+/// Iterator of overlapping windows built by [`Skak::windows`].
+pub struct Windows<I>
+where
+    I: Iterator,
+{
+    iter: I,
+    size: usize,
+    step: usize,
+    buf: VecDeque<I::Item>,
+}
+
+impl<I> Iterator for Windows<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = VecDeque<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.buf.len() < self.size {
+            match self.iter.next() {
+                Some(x) => self.buf.push_back(x),
+                None => break,
+            }
+        }
+
+        if self.buf.is_empty() {
+            return None;
+        }
+
+        let window = self.buf.clone();
+
+        // Keep only the tail that the next window shares with this one...
+        for _ in 0..self.step.min(self.buf.len()) {
+            self.buf.pop_front();
+        }
+        // ...and, if the step is wider than the window, skip the gap between them.
+        if self.step > self.size {
+            for _ in 0..(self.step - self.size) {
+                if self.iter.next().is_none() {
+                    break;
+                }
+            }
+        }
+
+        Some(window)
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -231,4 +593,111 @@ mod tests {
             count += 1;
         }
     }
+
+    #[test]
+    fn skip_next_back_respects_skipped_prefix() {
+        let v: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let mut skip = Skip::new(v.iter(), 3);
+        // Only items after the skipped prefix (indices 3..8) may ever come back out.
+        assert_eq!(skip.next_back(), Some(&8));
+        assert_eq!(skip.next_back(), Some(&7));
+        assert_eq!(skip.next_back(), Some(&6));
+        assert_eq!(skip.next_back(), Some(&5));
+        assert_eq!(skip.next_back(), Some(&4));
+        assert_eq!(skip.next_back(), None);
+        assert_eq!(skip.next(), None);
+    }
+
+    #[test]
+    fn skip_nth_back_past_len_drains_to_boundary() {
+        let v: Vec<i32> = vec![1, 2, 3, 4, 5];
+        let mut skip = Skip::new(v.iter(), 2);
+        assert_eq!(skip.len(), 3);
+        // n >= len must drain the remainder and report None, never a
+        // skipped-prefix item.
+        assert_eq!(skip.nth_back(10), None);
+        assert_eq!(skip.next(), None);
+        assert_eq!(skip.next_back(), None);
+    }
+
+    #[test]
+    fn array_chunks_exact_multiple_has_no_remainder() {
+        let v: Vec<i32> = vec![1, 2, 3, 4, 5, 6];
+        let mut chunks = Skak::array_chunks::<3>(v.into_iter());
+        assert_eq!(chunks.next(), Some([1, 2, 3]));
+        assert_eq!(chunks.next(), Some([4, 5, 6]));
+        assert_eq!(chunks.next(), None);
+        assert_eq!(chunks.into_remainder(), VecDeque::new());
+    }
+
+    #[test]
+    fn array_chunks_leftover_goes_to_remainder_in_order() {
+        let v: Vec<i32> = vec![1, 2, 3, 4, 5];
+        let mut chunks = Skak::array_chunks::<2>(v.into_iter());
+        assert_eq!(chunks.next(), Some([1, 2]));
+        assert_eq!(chunks.next(), Some([3, 4]));
+        assert_eq!(chunks.next(), None);
+        assert_eq!(chunks.into_remainder(), VecDeque::from([5]));
+    }
+
+    #[test]
+    fn array_chunks_drops_partial_fill_on_panic() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        struct DropCounter<'a>(&'a AtomicUsize);
+        impl Drop for DropCounter<'_> {
+            fn drop(&mut self) {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let drops = AtomicUsize::new(0);
+        // Two items filled, then the source panics mid-chunk: the already
+        // written slots must still be dropped rather than leaked.
+        let items: Vec<Option<DropCounter<'_>>> = vec![
+            Some(DropCounter(&drops)),
+            Some(DropCounter(&drops)),
+            None,
+        ];
+        let mut items = items.into_iter();
+        let mut iter = std::iter::from_fn(move || match items.next() {
+            Some(Some(item)) => Some(item),
+            Some(None) => panic!("boom"),
+            None => None,
+        });
+
+        let mut chunks = Skak::array_chunks::<3>(&mut iter);
+        let result = catch_unwind(AssertUnwindSafe(|| chunks.next()));
+        assert!(result.is_err());
+        assert_eq!(drops.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn windows_overlap_shares_tail_between_windows() {
+        let v: Vec<i32> = vec![1, 2, 3, 4, 5];
+        let mut windows = Skak::windows(v.into_iter(), 3, 1);
+        assert_eq!(windows.next(), Some(VecDeque::from([1, 2, 3])));
+        assert_eq!(windows.next(), Some(VecDeque::from([2, 3, 4])));
+        assert_eq!(windows.next(), Some(VecDeque::from([3, 4, 5])));
+        assert_eq!(windows.next(), Some(VecDeque::from([4, 5])));
+        assert_eq!(windows.next(), Some(VecDeque::from([5])));
+        assert_eq!(windows.next(), None);
+    }
+
+    #[test]
+    fn windows_step_wider_than_size_skips_a_gap() {
+        let v: Vec<i32> = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let mut windows = Skak::windows(v.into_iter(), 2, 4);
+        assert_eq!(windows.next(), Some(VecDeque::from([1, 2])));
+        assert_eq!(windows.next(), Some(VecDeque::from([5, 6])));
+        assert_eq!(windows.next(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn windows_rejects_zero_step() {
+        let v: Vec<i32> = vec![1, 2, 3];
+        let _ = Skak::windows(v.into_iter(), 2, 0);
+    }
 }